@@ -0,0 +1,214 @@
+//! Layered configuration loading: built-in defaults, an optional file, then
+//! environment-variable overrides.
+//!
+//! Sources are applied in order and merged field-by-field, so a later source
+//! only needs to specify the fields it wants to change.
+
+use std::env;
+use std::fs;
+use std::path::Path;
+
+use serde_json::Value;
+
+use crate::ManagerConfig;
+
+/// File formats recognized by [`ConfigBuilder::file`], selected by extension.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConfigFormat {
+    Toml,
+    Json,
+    Yaml,
+}
+
+impl ConfigFormat {
+    /// Guess the format from a file's extension (`.toml`, `.json`, `.yaml`/`.yml`).
+    pub fn from_extension<P: AsRef<Path>>(path: P) -> Result<Self, String> {
+        match path.as_ref().extension().and_then(|ext| ext.to_str()) {
+            Some("toml") => Ok(ConfigFormat::Toml),
+            Some("json") => Ok(ConfigFormat::Json),
+            Some("yaml") | Some("yml") => Ok(ConfigFormat::Yaml),
+            other => Err(format!("unrecognized config file extension: {:?}", other)),
+        }
+    }
+}
+
+/// Builds a [`ManagerConfig`] from layered sources, each overriding the last:
+/// built-in defaults, an optional config file (TOML/JSON/YAML, auto-detected by
+/// extension), then environment-variable overrides such as
+/// `PART_COMMCONFIG__DAD_GNC_PORT=6000`.
+pub struct ConfigBuilder {
+    env_prefix: String,
+    env_separator: String,
+    file: Option<Value>,
+}
+
+impl ConfigBuilder {
+    /// Start a new builder. Environment variables are matched using `PART_` as
+    /// the prefix and `__` as the nesting separator by default.
+    pub fn new() -> Self {
+        ConfigBuilder {
+            env_prefix: "PART_".to_string(),
+            env_separator: "__".to_string(),
+            file: None,
+        }
+    }
+
+    /// Override the environment-variable prefix (default: `PART_`).
+    pub fn env_prefix(mut self, prefix: &str) -> Self {
+        self.env_prefix = prefix.to_string();
+        self
+    }
+
+    /// Override the nesting separator used in environment-variable keys (default: `__`).
+    pub fn env_separator(mut self, separator: &str) -> Self {
+        self.env_separator = separator.to_string();
+        self
+    }
+
+    /// Layer a config file on top of the defaults. The format is auto-detected
+    /// from the file extension; use [`ConfigBuilder::file_with_format`] to
+    /// override that.
+    pub fn file<P: AsRef<Path>>(self, path: P) -> Result<Self, Box<dyn std::error::Error>> {
+        let format = ConfigFormat::from_extension(&path)?;
+        self.file_with_format(path, format)
+    }
+
+    /// Layer a config file on top of the defaults, using an explicit format.
+    pub fn file_with_format<P: AsRef<Path>>(
+        mut self,
+        path: P,
+        format: ConfigFormat,
+    ) -> Result<Self, Box<dyn std::error::Error>> {
+        let content = fs::read_to_string(path)?;
+        let value: Value = match format {
+            ConfigFormat::Toml => toml::de::from_str(&content)?,
+            ConfigFormat::Json => serde_json::from_str(&content)?,
+            ConfigFormat::Yaml => serde_yaml::from_str(&content)?,
+        };
+        self.file = Some(value);
+        Ok(self)
+    }
+
+    /// Compose the defaults, optional file, and environment overrides into a
+    /// complete [`ManagerConfig`].
+    pub fn build(self) -> Result<ManagerConfig, Box<dyn std::error::Error>> {
+        let mut merged = serde_json::to_value(ManagerConfig::default())?;
+        if let Some(file) = &self.file {
+            merge_values(&mut merged, file);
+        }
+        apply_env_overrides(&mut merged, &self.env_prefix, &self.env_separator)?;
+        let mut config: ManagerConfig = serde_json::from_value(merged)?;
+        let remap = config.path_remap.clone();
+        crate::path_remap::resolve_paths(&mut config, &remap);
+        Ok(config)
+    }
+}
+
+impl Default for ConfigBuilder {
+    fn default() -> Self {
+        ConfigBuilder::new()
+    }
+}
+
+/// Recursively overlay `patch` onto `base`, replacing only the fields present
+/// in `patch` so a partial source doesn't blank out the rest of the config.
+fn merge_values(base: &mut Value, patch: &Value) {
+    match (base, patch) {
+        (Value::Object(base_map), Value::Object(patch_map)) => {
+            for (key, value) in patch_map {
+                merge_values(base_map.entry(key.clone()).or_insert(Value::Null), value);
+            }
+        }
+        (base_slot, value) => {
+            *base_slot = value.clone();
+        }
+    }
+}
+
+/// Walk every `<prefix><separator-joined path>` environment variable and set
+/// the corresponding nested field in `root`.
+fn apply_env_overrides(
+    root: &mut Value,
+    prefix: &str,
+    separator: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    for (key, raw_value) in env::vars() {
+        let Some(rest) = key.strip_prefix(prefix) else {
+            continue;
+        };
+        let path: Vec<String> = rest
+            .to_lowercase()
+            .split(separator)
+            .map(String::from)
+            .collect();
+        set_path(root, &path, &raw_value)
+            .map_err(|e| format!("invalid override for '{}': {}", key, e))?;
+    }
+    Ok(())
+}
+
+/// Set a segmented path inside a JSON value, parsing `raw_value` into whatever
+/// type already lives at that field (number, bool, or string).
+fn set_path(root: &mut Value, path: &[String], raw_value: &str) -> Result<(), String> {
+    let (last, ancestors) = path
+        .split_last()
+        .ok_or_else(|| "empty environment override path".to_string())?;
+    let mut cursor = root;
+    for segment in ancestors {
+        cursor = cursor
+            .as_object_mut()
+            .ok_or_else(|| format!("cannot descend into non-object at '{}'", segment))?
+            .entry(segment.clone())
+            .or_insert_with(|| Value::Object(Default::default()));
+    }
+    let map = cursor
+        .as_object_mut()
+        .ok_or_else(|| format!("cannot set field '{}' on a non-object", last))?;
+    let parsed = match map.get(last) {
+        Some(Value::Number(existing)) if existing.is_i64() || existing.is_u64() => raw_value
+            .parse::<i64>()
+            .map(Value::from)
+            .map_err(|_| format!("expected an integer for '{}', got '{}'", last, raw_value))?,
+        Some(Value::Number(_)) => raw_value
+            .parse::<f64>()
+            .ok()
+            .and_then(serde_json::Number::from_f64)
+            .map(Value::Number)
+            .ok_or_else(|| format!("expected a number for '{}', got '{}'", last, raw_value))?,
+        Some(Value::Bool(_)) => raw_value
+            .parse::<bool>()
+            .map(Value::Bool)
+            .map_err(|_| format!("expected a bool for '{}', got '{}'", last, raw_value))?,
+        _ => Value::String(raw_value.to_string()),
+    };
+    map.insert(last.clone(), parsed);
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn env_override_preserves_integer_type() {
+        let key = "CONFIG_BUILDER_TEST__COMMCONFIG__DAD_GNC_PORT";
+        std::env::set_var(key, "6000");
+
+        let config = ConfigBuilder::new()
+            .env_prefix("CONFIG_BUILDER_TEST__")
+            .build();
+
+        std::env::remove_var(key);
+
+        let config = config.unwrap();
+        assert_eq!(config.commconfig.dad_gnc_port, 6000);
+    }
+
+    #[test]
+    fn merge_values_overlays_only_present_fields() {
+        let mut base = serde_json::json!({"a": 1, "b": {"c": 2, "d": 3}});
+        let patch = serde_json::json!({"b": {"c": 20}});
+        merge_values(&mut base, &patch);
+        assert_eq!(base, serde_json::json!({"a": 1, "b": {"c": 20, "d": 3}}));
+    }
+}
@@ -0,0 +1,31 @@
+//! Discovery of a per-user default config, so tools and flight software share
+//! one convention instead of each hard-coding a filename.
+
+use std::path::PathBuf;
+
+use directories::ProjectDirs;
+
+use crate::{generate_config, ConfigBuilder, ManagerConfig};
+
+/// The standard per-user location for the default config, e.g.
+/// `~/.config/part-2025/config.toml` on Linux.
+pub fn default_config_path() -> Result<PathBuf, Box<dyn std::error::Error>> {
+    let dirs = ProjectDirs::from("", "", "part-2025")
+        .ok_or("could not determine the OS config directory")?;
+    Ok(dirs.config_dir().join("config.toml"))
+}
+
+/// Load the default config, creating a fully-defaulted file at
+/// [`default_config_path`] on first run if none exists. A config found on
+/// disk is merged over the built-in defaults via [`ConfigBuilder`], so a
+/// partial file still yields a complete [`ManagerConfig`].
+pub fn load_or_init() -> Result<ManagerConfig, Box<dyn std::error::Error>> {
+    let path = default_config_path()?;
+    if !path.exists() {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        generate_config(path.to_string_lossy().into_owned(), ManagerConfig::default())?;
+    }
+    ConfigBuilder::new().file(&path)?.build()
+}
@@ -3,12 +3,24 @@
 //! This library defines the various configuration settings used throughout the project,
 //! including model configurations, aircraft properties, coordinates, and communication settings.
 
+pub mod config_builder;
+pub mod default_config;
+pub mod openair;
+pub mod path_remap;
+pub mod validate;
+
 use serde::Deserialize;
 use serde::Serialize;
 use std::fs;
 use std::path::Path;
 use toml::to_string;
 
+pub use config_builder::{ConfigBuilder, ConfigFormat};
+pub use default_config::{default_config_path, load_or_init};
+pub use openair::from_openair;
+pub use path_remap::{resolve_paths, PathRemap, PathRemapConfig};
+pub use validate::{validate, ConfigError};
+
 /// Configuration settings for the global project.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct ManagerConfig {
@@ -17,6 +29,8 @@ pub struct ManagerConfig {
     pub aircraft_properties: AircraftProperties,
     pub coordinates: Coordinates,
     pub commconfig: CommConfig,
+    #[serde(default)]
+    pub path_remap: PathRemapConfig,
 }
 
 /// Configuration for the YOLO model.
@@ -54,6 +68,15 @@ pub struct AircraftProperties {
     pub velocity: f64,
 }
 
+impl Default for AircraftProperties {
+    fn default() -> Self {
+        AircraftProperties {
+            turn_radius: 10.0,
+            velocity: 15.0,
+        }
+    }
+}
+
 /// Represents various coordinate sets used in competition.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct Coordinates {
@@ -62,6 +85,28 @@ pub struct Coordinates {
     pub target_area: Vec<Point>,
     pub flying_threshold: f64,
     pub mapping_threshold: f64,
+    pub no_fly_zones: Vec<Polygon>,
+}
+
+impl Default for Coordinates {
+    fn default() -> Self {
+        Coordinates {
+            waypoints: Vec::new(),
+            mapping_area: vec![
+                Point { x: 0.0, y: 0.0 },
+                Point { x: 1.0, y: 0.0 },
+                Point { x: 0.0, y: 1.0 },
+            ],
+            target_area: vec![
+                Point { x: 2.0, y: 2.0 },
+                Point { x: 3.0, y: 2.0 },
+                Point { x: 2.0, y: 3.0 },
+            ],
+            flying_threshold: 100.0,
+            mapping_threshold: 50.0,
+            no_fly_zones: Vec::new(),
+        }
+    }
 }
 
 /// A point in 2D space.
@@ -71,6 +116,21 @@ pub struct Point {
     pub y: f64,
 }
 
+impl Default for Point {
+    fn default() -> Self {
+        Point { x: 0.0, y: 0.0 }
+    }
+}
+
+/// A named airspace polygon, such as a no-fly zone imported from an OpenAir file.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Polygon {
+    pub name: String,
+    pub points: Vec<Point>,
+    pub lower_altitude_ft: Option<f64>,
+    pub upper_altitude_ft: Option<f64>,
+}
+
 /// Communication settings between all processes.
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct CommConfig {
@@ -82,18 +142,53 @@ pub struct CommConfig {
     pub flightcomputer_ip: String,
 }
 
-/// Create a ManagerConfig file given a filepath of a .toml to read from
+impl Default for CommConfig {
+    fn default() -> Self {
+        CommConfig {
+            dad_gnc_port: 5000,
+            gnc_dad_port: 5001,
+            dad_sauron_port: 5002,
+            sauron_dad_port: 5003,
+            groundstation_ip: "127.0.0.1".to_string(),
+            flightcomputer_ip: "127.0.0.2".to_string(),
+        }
+    }
+}
+
+impl Default for ManagerConfig {
+    fn default() -> Self {
+        ManagerConfig {
+            test: true,
+            sauron_config: SauronConfig::default(),
+            aircraft_properties: AircraftProperties::default(),
+            coordinates: Coordinates::default(),
+            commconfig: CommConfig::default(),
+            path_remap: PathRemapConfig::default(),
+        }
+    }
+}
+
+/// Create a ManagerConfig file given a filepath of a .toml to read from.
+/// Any `[path_remap]` rules present in the file are applied immediately, so
+/// callers always see machine-local absolute paths.
 pub fn read_config<P: AsRef<Path>>(path: P) -> Result<ManagerConfig, Box<dyn std::error::Error>> {
     let content = fs::read_to_string(path)?;
-    let config: ManagerConfig = toml::de::from_str(&content)?;
+    let mut config: ManagerConfig = toml::de::from_str(&content)?;
+    let remap = config.path_remap.clone();
+    path_remap::resolve_paths(&mut config, &remap);
     Ok(config)
 }
 
-/// Write a ManagerConfig to a .toml file given the filepath
+/// Write a ManagerConfig to a .toml file given the filepath, refusing to
+/// write a config that fails [`validate`].
 pub fn generate_config(
     file_name: String,
     config: ManagerConfig,
 ) -> Result<(), Box<dyn std::error::Error>> {
+    if let Err(errors) = validate::validate(&config) {
+        let messages: Vec<String> = errors.iter().map(|e| e.to_string()).collect();
+        return Err(format!("configuration is invalid:\n{}", messages.join("\n")).into());
+    }
     fs::write(file_name, to_string(&config)?)?;
     println!("Configuration file generation: SUCCESS");
     Ok(())
@@ -1,13 +1,96 @@
 //! This program runs a terminal interface which allows the user to generate a global config file
-//! used by the rest of the program.
+//! used by the rest of the program. It can also be driven non-interactively via subcommands for
+//! use in scripts, CI, and remote deployment.
 
 use std::any::type_name;
 use std::io::{self, Write};
 use std::net::IpAddr;
 use std::str::FromStr;
 
+use clap::{Parser, Subcommand};
+
 use configuranator_demo::*;
 
+/// Generate and validate PART global configuration files.
+#[derive(Parser)]
+#[command(name = "configuranator", about = "Generate and validate PART ManagerConfig files")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+#[allow(clippy::large_enum_variant)]
+enum Command {
+    /// Build a config entirely from flags, with no prompts.
+    Generate {
+        /// Output file name (must end in .toml).
+        #[arg(long)]
+        file_name: String,
+        #[arg(long, default_value_t = false)]
+        test: bool,
+        #[arg(long, default_value_t = AircraftProperties::default().turn_radius)]
+        turn_radius: f64,
+        #[arg(long, default_value_t = AircraftProperties::default().velocity)]
+        velocity: f64,
+        /// A waypoint, in "x,y" form. Repeat to add more.
+        #[arg(long = "waypoint")]
+        waypoints: Vec<String>,
+        /// A mapping-area vertex, in "x,y" form. Repeat to add more.
+        #[arg(long = "mapping-point")]
+        mapping_area: Vec<String>,
+        /// A target-area vertex, in "x,y" form. Repeat to add more.
+        #[arg(long = "target-point")]
+        target_area: Vec<String>,
+        #[arg(long, default_value_t = Coordinates::default().flying_threshold)]
+        flying_threshold: f64,
+        #[arg(long, default_value_t = Coordinates::default().mapping_threshold)]
+        mapping_threshold: f64,
+        #[arg(long, default_value_t = CommConfig::default().dad_gnc_port)]
+        dad_gnc_port: i32,
+        #[arg(long, default_value_t = CommConfig::default().gnc_dad_port)]
+        gnc_dad_port: i32,
+        #[arg(long, default_value_t = CommConfig::default().dad_sauron_port)]
+        dad_sauron_port: i32,
+        #[arg(long, default_value_t = CommConfig::default().sauron_dad_port)]
+        sauron_dad_port: i32,
+        #[arg(long, default_value_t = CommConfig::default().groundstation_ip)]
+        groundstation_ip: String,
+        #[arg(long, default_value_t = CommConfig::default().flightcomputer_ip)]
+        flightcomputer_ip: String,
+        #[arg(long, default_value_t = SauronConfig::default().model_path)]
+        model_path: String,
+        #[arg(long, default_value_t = SauronConfig::default().input_size)]
+        input_size: i32,
+        #[arg(long, default_value_t = SauronConfig::default().dataset_name)]
+        dataset_name: String,
+        /// Camera field of view, in "horizontal,vertical" form.
+        #[arg(long, default_value_t = format!("{},{}", SauronConfig::default().fov.0, SauronConfig::default().fov.1))]
+        fov: String,
+        /// Camera resolution, in "width,height" form.
+        #[arg(long, default_value_t = format!("{},{}", SauronConfig::default().resolution.0, SauronConfig::default().resolution.1))]
+        resolution: String,
+        #[arg(long, default_value_t = SauronConfig::default().untagged_image_folder)]
+        untagged_image_folder: String,
+        #[arg(long, default_value_t = SauronConfig::default().detection_image_folder)]
+        detection_image_folder: String,
+        #[arg(long, default_value_t = SauronConfig::default().mapping_image_folder)]
+        mapping_image_folder: String,
+    },
+    /// Validate an existing config file without writing anything.
+    Validate {
+        /// Path to the .toml config file to check.
+        file: String,
+    },
+    /// Parse and print an existing config file.
+    Show {
+        /// Path to the .toml config file to display.
+        file: String,
+    },
+    /// Run the guided, prompt-driven flow (the previous default behavior).
+    Interactive,
+}
+
 /// Prompts the user for a string input.
 pub fn prompt_str_input(prompt: &str) -> String {
     print!("{}", prompt);
@@ -92,8 +175,18 @@ where
     Ok((first, second))
 }
 
-/// Runs the program to get parameters and generate a configuration file.
-fn main() -> Result<(), Box<dyn std::error::Error>> {
+/// Parse a repeated `--waypoint x,y`-style flag into a [`Point`].
+fn point_from_flag(raw: &str) -> Result<Point, Box<dyn std::error::Error>> {
+    let (x, y) = parse_tuple::<f64>(raw)?;
+    Ok(Point { x, y })
+}
+
+fn points_from_flags(raw: &[String]) -> Result<Vec<Point>, Box<dyn std::error::Error>> {
+    raw.iter().map(|s| point_from_flag(s)).collect()
+}
+
+/// Runs the guided, prompt-driven flow to build and write a config file.
+fn interactive() -> Result<(), Box<dyn std::error::Error>> {
     let mut file_name: String =
         prompt("Enter the configuration file name (e.g., test_config.toml): ");
     while !file_name.ends_with(".toml") {
@@ -165,6 +258,7 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             target_area,
             flying_threshold,
             mapping_threshold,
+            no_fly_zones: Vec::new(),
         },
         commconfig: CommConfig {
             dad_gnc_port,
@@ -174,7 +268,158 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
             groundstation_ip: groundstation_ip.to_string(),
             flightcomputer_ip: flightcomputer_ip.to_string(),
         },
+        path_remap: PathRemapConfig::default(),
+    };
+
+    generate_config(file_name, config)
+}
+
+/// Builds a config entirely from CLI flags, validates it, and writes it out.
+#[allow(clippy::too_many_arguments)]
+fn generate_from_flags(
+    file_name: String,
+    test: bool,
+    turn_radius: f64,
+    velocity: f64,
+    waypoints: Vec<String>,
+    mapping_area: Vec<String>,
+    target_area: Vec<String>,
+    flying_threshold: f64,
+    mapping_threshold: f64,
+    dad_gnc_port: i32,
+    gnc_dad_port: i32,
+    dad_sauron_port: i32,
+    sauron_dad_port: i32,
+    groundstation_ip: String,
+    flightcomputer_ip: String,
+    model_path: String,
+    input_size: i32,
+    dataset_name: String,
+    fov: String,
+    resolution: String,
+    untagged_image_folder: String,
+    detection_image_folder: String,
+    mapping_image_folder: String,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !file_name.ends_with(".toml") {
+        return Err("The file name must end with '.toml'.".into());
+    }
+
+    let config = ManagerConfig {
+        test,
+        sauron_config: SauronConfig {
+            model_path,
+            input_size,
+            dataset_name,
+            fov: parse_tuple::<f64>(&fov)?,
+            resolution: parse_tuple::<i32>(&resolution)?,
+            untagged_image_folder,
+            detection_image_folder,
+            mapping_image_folder,
+        },
+        aircraft_properties: AircraftProperties {
+            turn_radius,
+            velocity,
+        },
+        coordinates: Coordinates {
+            waypoints: points_from_flags(&waypoints)?,
+            mapping_area: points_from_flags(&mapping_area)?,
+            target_area: points_from_flags(&target_area)?,
+            flying_threshold,
+            mapping_threshold,
+            no_fly_zones: Vec::new(),
+        },
+        commconfig: CommConfig {
+            dad_gnc_port,
+            gnc_dad_port,
+            dad_sauron_port,
+            sauron_dad_port,
+            groundstation_ip,
+            flightcomputer_ip,
+        },
+        path_remap: PathRemapConfig::default(),
     };
 
     generate_config(file_name, config)
 }
+
+fn validate_file(file: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let config = read_config(file)?;
+    match validate(&config) {
+        Ok(()) => {
+            println!("{}: valid", file);
+            Ok(())
+        }
+        Err(errors) => {
+            for error in &errors {
+                eprintln!("{}", error);
+            }
+            Err(format!("{}: {} validation error(s)", file, errors.len()).into())
+        }
+    }
+}
+
+fn show_file(file: &str) -> Result<(), Box<dyn std::error::Error>> {
+    let config = read_config(file)?;
+    println!("{:#?}", config);
+    Ok(())
+}
+
+fn main() -> Result<(), Box<dyn std::error::Error>> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Generate {
+            file_name,
+            test,
+            turn_radius,
+            velocity,
+            waypoints,
+            mapping_area,
+            target_area,
+            flying_threshold,
+            mapping_threshold,
+            dad_gnc_port,
+            gnc_dad_port,
+            dad_sauron_port,
+            sauron_dad_port,
+            groundstation_ip,
+            flightcomputer_ip,
+            model_path,
+            input_size,
+            dataset_name,
+            fov,
+            resolution,
+            untagged_image_folder,
+            detection_image_folder,
+            mapping_image_folder,
+        } => generate_from_flags(
+            file_name,
+            test,
+            turn_radius,
+            velocity,
+            waypoints,
+            mapping_area,
+            target_area,
+            flying_threshold,
+            mapping_threshold,
+            dad_gnc_port,
+            gnc_dad_port,
+            dad_sauron_port,
+            sauron_dad_port,
+            groundstation_ip,
+            flightcomputer_ip,
+            model_path,
+            input_size,
+            dataset_name,
+            fov,
+            resolution,
+            untagged_image_folder,
+            detection_image_folder,
+            mapping_image_folder,
+        ),
+        Command::Validate { file } => validate_file(&file),
+        Command::Show { file } => show_file(&file),
+        Command::Interactive => interactive(),
+    }
+}
@@ -0,0 +1,306 @@
+//! Importer for the line-based OpenAir airspace format, used to populate
+//! [`Coordinates`] without hand-entering polygon vertices through the
+//! terminal prompts.
+
+use std::io::BufRead;
+
+use crate::{Point, Polygon};
+
+/// The result of importing an OpenAir file: any airspace whose name matched a
+/// mapping/target tag is flattened into plain point lists, and everything
+/// else becomes a no-fly zone polygon.
+#[derive(Debug, Clone, Default)]
+pub struct OpenAirImport {
+    pub mapping_area: Vec<Point>,
+    pub target_area: Vec<Point>,
+    pub no_fly_zones: Vec<Polygon>,
+}
+
+/// Number of points used to approximate a `DC` circle/arc as a polygon.
+const CIRCLE_SAMPLES: usize = 36;
+
+/// Nautical miles to degrees of latitude, used to size approximated circles.
+const NM_TO_DEG: f64 = 1.0 / 60.0;
+
+#[derive(Debug, Default)]
+struct Airspace {
+    name: Option<String>,
+    lower_altitude_ft: Option<f64>,
+    upper_altitude_ft: Option<f64>,
+    points: Vec<Point>,
+    center: Option<Point>,
+}
+
+impl Airspace {
+    fn into_polygon(self) -> Option<Polygon> {
+        if self.points.is_empty() {
+            return None;
+        }
+        Some(Polygon {
+            name: self.name.unwrap_or_default(),
+            points: self.points,
+            lower_altitude_ft: self.lower_altitude_ft,
+            upper_altitude_ft: self.upper_altitude_ft,
+        })
+    }
+}
+
+/// Parse an OpenAir airspace file, routing airspaces whose name matches
+/// `mapping_tags`/`target_tags` (case-insensitive substring match) into
+/// `mapping_area`/`target_area`, and everything else into `no_fly_zones`.
+pub fn from_openair<R: BufRead>(
+    reader: R,
+    mapping_tags: &[&str],
+    target_tags: &[&str],
+) -> Result<OpenAirImport, Box<dyn std::error::Error>> {
+    let mut import = OpenAirImport::default();
+    let mut current: Option<Airspace> = None;
+
+    for line in reader.lines() {
+        let line = line?;
+        let line = line.trim();
+        if line.is_empty() || line.starts_with('*') || line.starts_with('#') {
+            continue;
+        }
+
+        let (tag, rest) = match line.split_once(' ') {
+            Some((tag, rest)) => (tag, rest.trim()),
+            None => (line, ""),
+        };
+
+        match tag {
+            "AC" => {
+                finish_airspace(current.take(), mapping_tags, target_tags, &mut import);
+                current = Some(Airspace::default());
+            }
+            "AN" => {
+                if let Some(airspace) = current.as_mut() {
+                    airspace.name = Some(rest.to_string());
+                }
+            }
+            "AL" => {
+                if let Some(airspace) = current.as_mut() {
+                    airspace.lower_altitude_ft = parse_altitude_ft(rest);
+                }
+            }
+            "AH" => {
+                if let Some(airspace) = current.as_mut() {
+                    airspace.upper_altitude_ft = parse_altitude_ft(rest);
+                }
+            }
+            "DP" => {
+                if let Some(airspace) = current.as_mut() {
+                    airspace.points.push(parse_coordinate(rest)?);
+                }
+            }
+            "V" => {
+                if let Some((key, value)) = rest.split_once('=') {
+                    if key.trim() == "X" {
+                        if let Some(airspace) = current.as_mut() {
+                            airspace.center = Some(parse_coordinate(value.trim())?);
+                        }
+                    }
+                }
+            }
+            "DC" => {
+                if let Some(airspace) = current.as_mut() {
+                    let radius_nm: f64 = rest
+                        .trim()
+                        .parse()
+                        .map_err(|_| format!("invalid DC radius: '{}'", rest))?;
+                    let center = airspace
+                        .center
+                        .clone()
+                        .ok_or("DC record with no preceding V X= center")?;
+                    airspace.points = sample_circle(center, radius_nm);
+                }
+            }
+            "AT" => {
+                // Label placement hint; not needed for polygon geometry.
+            }
+            _ => {
+                // Unrecognized record type; ignore rather than fail the whole import.
+            }
+        }
+    }
+    finish_airspace(current.take(), mapping_tags, target_tags, &mut import);
+
+    Ok(import)
+}
+
+fn finish_airspace(
+    airspace: Option<Airspace>,
+    mapping_tags: &[&str],
+    target_tags: &[&str],
+    import: &mut OpenAirImport,
+) {
+    let Some(airspace) = airspace else {
+        return;
+    };
+    let name = airspace.name.clone().unwrap_or_default();
+    let name_lower = name.to_lowercase();
+    let matches_any = |tags: &[&str]| tags.iter().any(|tag| name_lower.contains(&tag.to_lowercase()));
+
+    if matches_any(mapping_tags) {
+        import.mapping_area = airspace.points;
+    } else if matches_any(target_tags) {
+        import.target_area = airspace.points;
+    } else if let Some(polygon) = airspace.into_polygon() {
+        import.no_fly_zones.push(polygon);
+    }
+}
+
+/// Approximate a circle of `radius_nm` nautical miles centered at `center` as
+/// a closed polygon with [`CIRCLE_SAMPLES`] vertices. Longitude degrees are
+/// narrower than latitude degrees by `cos(latitude)`, so the east-west radius
+/// is widened accordingly to keep the polygon physically circular.
+fn sample_circle(center: Point, radius_nm: f64) -> Vec<Point> {
+    let radius_deg = radius_nm * NM_TO_DEG;
+    let lon_scale = center.y.to_radians().cos();
+    let lon_radius_deg = if lon_scale.abs() > 1e-6 {
+        radius_deg / lon_scale
+    } else {
+        radius_deg
+    };
+    (0..CIRCLE_SAMPLES)
+        .map(|i| {
+            let angle = 2.0 * std::f64::consts::PI * (i as f64) / (CIRCLE_SAMPLES as f64);
+            Point {
+                x: center.x + lon_radius_deg * angle.cos(),
+                y: center.y + radius_deg * angle.sin(),
+            }
+        })
+        .collect()
+}
+
+fn parse_altitude_ft(s: &str) -> Option<f64> {
+    let digits: String = s.chars().filter(|c| c.is_ascii_digit() || *c == '.').collect();
+    let value: f64 = digits.parse().ok()?;
+    // Flight levels are given in hundreds of feet.
+    if s.trim().to_ascii_uppercase().starts_with("FL") {
+        Some(value * 100.0)
+    } else {
+        Some(value)
+    }
+}
+
+/// Parse a `DP`/`V X=` coordinate, either `DD:MM:SS [N/S] DDD:MM:SS [E/W]` or
+/// signed decimal degrees (`lat lon`), into our `Point { x: lon, y: lat }`.
+fn parse_coordinate(s: &str) -> Result<Point, Box<dyn std::error::Error>> {
+    let s = s.trim();
+    let parts: Vec<&str> = s.split(',').map(str::trim).collect();
+    let parts: Vec<&str> = if parts.len() == 2 {
+        parts
+    } else {
+        s.split_whitespace().collect()
+    };
+
+    if parts.len() == 2 {
+        // Signed decimal degrees: "51.5072 -0.1276" (lat lon).
+        if let (Ok(lat), Ok(lon)) = (parts[0].parse::<f64>(), parts[1].parse::<f64>()) {
+            return Ok(Point { x: lon, y: lat });
+        }
+    }
+
+    // DMS format split across tokens, e.g. ["51:06:43", "N", "002:32:23", "E"]
+    // or glued together, e.g. ["51:06:43N", "002:32:23E"].
+    let joined: String = parts.join(" ");
+    let tokens: Vec<&str> = joined.split_whitespace().collect();
+    let (lat_tokens, lon_tokens) = split_dms_tokens(&tokens)?;
+
+    let lat = parse_dms(&lat_tokens)?;
+    let lon = parse_dms(&lon_tokens)?;
+    Ok(Point { x: lon, y: lat })
+}
+
+/// Split a token stream into the latitude half and longitude half of a DMS
+/// coordinate, handling both `"DD:MM:SS N"` and `"DD:MM:SSN"` spellings.
+fn split_dms_tokens<'a>(tokens: &[&'a str]) -> Result<(Vec<&'a str>, Vec<&'a str>), String> {
+    let mut halves: Vec<Vec<&str>> = Vec::new();
+    let mut current: Vec<&str> = Vec::new();
+    for &token in tokens {
+        current.push(token);
+        if token.ends_with(['N', 'S', 'E', 'W']) || ["N", "S", "E", "W"].contains(&token) {
+            halves.push(std::mem::take(&mut current));
+        }
+    }
+    if !current.is_empty() {
+        halves.push(current);
+    }
+    if halves.len() != 2 {
+        return Err(format!("could not split coordinate into lat/lon: {:?}", tokens));
+    }
+    let mut iter = halves.into_iter();
+    Ok((iter.next().unwrap(), iter.next().unwrap()))
+}
+
+/// Parse a `DD:MM:SS [N/S/E/W]` (or glued `DD:MM:SSN`) token group into signed
+/// decimal degrees.
+fn parse_dms(tokens: &[&str]) -> Result<f64, String> {
+    let joined = tokens.join(" ");
+    let hemisphere = joined
+        .chars()
+        .rev()
+        .find(|c| "NSEW".contains(*c))
+        .ok_or_else(|| format!("missing hemisphere in '{}'", joined))?;
+    let digits = joined.replace(['N', 'S', 'E', 'W'], "");
+    let fields: Vec<&str> = digits.trim().split(':').map(str::trim).collect();
+    if fields.is_empty() || fields.len() > 3 {
+        return Err(format!("malformed DMS value: '{}'", joined));
+    }
+    let degrees: f64 = fields[0].parse().map_err(|_| format!("bad degrees in '{}'", joined))?;
+    let minutes: f64 = fields
+        .get(1)
+        .map(|m| m.parse())
+        .transpose()
+        .map_err(|_| format!("bad minutes in '{}'", joined))?
+        .unwrap_or(0.0);
+    let seconds: f64 = fields
+        .get(2)
+        .map(|s| s.parse())
+        .transpose()
+        .map_err(|_| format!("bad seconds in '{}'", joined))?
+        .unwrap_or(0.0);
+
+    let magnitude = degrees + minutes / 60.0 + seconds / 3600.0;
+    Ok(match hemisphere {
+        'S' | 'W' => -magnitude,
+        _ => magnitude,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Cursor;
+
+    #[test]
+    fn parses_glued_dms_tokens() {
+        let point = parse_coordinate("51:06:43N 002:32:23E").unwrap();
+        assert!((point.y - (51.0 + 6.0 / 60.0 + 43.0 / 3600.0)).abs() < 1e-6);
+        assert!((point.x - (2.0 + 32.0 / 60.0 + 23.0 / 3600.0)).abs() < 1e-6);
+    }
+
+    #[test]
+    fn samples_dc_circle_around_v_x_center() {
+        let input = "\
+AC R
+AN TEST CIRCLE
+V X=40:00:00N 080:00:00W
+DC 5
+";
+        let import = from_openair(Cursor::new(input), &[], &[]).unwrap();
+        assert_eq!(import.no_fly_zones.len(), 1);
+        let circle = &import.no_fly_zones[0];
+        assert_eq!(circle.points.len(), CIRCLE_SAMPLES);
+        let center = Point { x: -80.0, y: 40.0 };
+        for point in &circle.points {
+            let lon_scale = center.y.to_radians().cos();
+            let dx = (point.x - center.x) * lon_scale;
+            let dy = point.y - center.y;
+            let radius_deg = (dx * dx + dy * dy).sqrt();
+            let expected_deg = 5.0 * NM_TO_DEG;
+            assert!((radius_deg - expected_deg).abs() < 1e-3);
+        }
+    }
+}
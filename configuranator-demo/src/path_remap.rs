@@ -0,0 +1,100 @@
+//! Prefix remapping for filesystem paths baked into [`ManagerConfig`], so a
+//! single canonical config can be shared across machines with differing
+//! directory layouts (e.g. the ground station and the flight computer).
+
+use serde::{Deserialize, Serialize};
+
+use crate::ManagerConfig;
+
+/// An ordered `from -> to` prefix substitution, applied by [`resolve_paths`].
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct PathRemap {
+    pub from: String,
+    pub to: String,
+}
+
+/// The `[path_remap]` section of a config: an ordered list of prefix
+/// substitutions, applied in order, first match wins.
+#[derive(Debug, Serialize, Deserialize, Clone, Default)]
+pub struct PathRemapConfig {
+    #[serde(default)]
+    pub rules: Vec<PathRemap>,
+}
+
+impl PathRemapConfig {
+    /// Rewrite `path` by replacing the first matching `from` prefix with its
+    /// `to` replacement. Paths that match no rule are left untouched. A match
+    /// only counts at a path component boundary, so `from = "/feonix-images"`
+    /// does not also match a sibling directory like `/feonix-images-backup`.
+    fn apply(&self, path: &str) -> String {
+        for rule in &self.rules {
+            if let Some(suffix) = path.strip_prefix(rule.from.as_str()) {
+                if suffix.is_empty() || suffix.starts_with('/') {
+                    return format!("{}{}", rule.to, suffix);
+                }
+            }
+        }
+        path.to_string()
+    }
+}
+
+/// Rewrite every stored filesystem path in `config.sauron_config` whose
+/// prefix matches one of `remap`'s rules, so the rest of the program always
+/// sees machine-local absolute paths.
+pub fn resolve_paths(config: &mut ManagerConfig, remap: &PathRemapConfig) {
+    let sauron = &mut config.sauron_config;
+    sauron.model_path = remap.apply(&sauron.model_path);
+    sauron.untagged_image_folder = remap.apply(&sauron.untagged_image_folder);
+    sauron.detection_image_folder = remap.apply(&sauron.detection_image_folder);
+    sauron.mapping_image_folder = remap.apply(&sauron.mapping_image_folder);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn remap(rules: &[(&str, &str)]) -> PathRemapConfig {
+        PathRemapConfig {
+            rules: rules
+                .iter()
+                .map(|(from, to)| PathRemap {
+                    from: from.to_string(),
+                    to: to.to_string(),
+                })
+                .collect(),
+        }
+    }
+
+    #[test]
+    fn passes_through_unmatched_paths() {
+        let remap = remap(&[("/feonix-images", "/mnt/images")]);
+        assert_eq!(remap.apply("/other/path/model.onnx"), "/other/path/model.onnx");
+    }
+
+    #[test]
+    fn first_matching_rule_wins() {
+        let remap = remap(&[
+            ("/feonix-images", "/mnt/images"),
+            ("/feonix-images/untagged", "/mnt/untagged-only"),
+        ]);
+        assert_eq!(
+            remap.apply("/feonix-images/untagged"),
+            "/mnt/images/untagged"
+        );
+    }
+
+    #[test]
+    fn does_not_match_sibling_directory_sharing_a_prefix() {
+        let remap = remap(&[("/feonix-images", "/mnt/images")]);
+        assert_eq!(
+            remap.apply("/feonix-images-backup/model.onnx"),
+            "/feonix-images-backup/model.onnx"
+        );
+    }
+
+    #[test]
+    fn matches_exact_path_with_no_suffix() {
+        let remap = remap(&[("/feonix-images", "/mnt/images")]);
+        assert_eq!(remap.apply("/feonix-images"), "/mnt/images");
+    }
+}
@@ -0,0 +1,261 @@
+//! Structured validation of a [`ManagerConfig`] before it is written to disk
+//! or trusted by the flight software.
+
+use std::fmt;
+
+use crate::{ManagerConfig, Point};
+
+/// A single reason a [`ManagerConfig`] was rejected by [`validate`].
+#[derive(Debug, Clone, PartialEq)]
+pub enum ConfigError {
+    /// A polygon field has fewer than 3 points, or all of its points are collinear.
+    DegeneratePolygon { field: &'static str, points: usize },
+    /// Two or more fields share the same port number.
+    DuplicatePort { port: i32, fields: Vec<&'static str> },
+    /// A field that must be positive was zero or negative.
+    NonPositive { field: &'static str, value: f64 },
+    /// The ground station and flight computer share the same IP address.
+    DuplicateIp { ip: String },
+    /// `fov` has a component outside the open interval (0, 180).
+    FovOutOfRange { fov: (f64, f64) },
+    /// `mapping_threshold` exceeds `flying_threshold`.
+    ThresholdInversion {
+        mapping_threshold: f64,
+        flying_threshold: f64,
+    },
+}
+
+impl fmt::Display for ConfigError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ConfigError::DegeneratePolygon { field, points } => write!(
+                f,
+                "{} must have at least 3 non-collinear points, got {}",
+                field, points
+            ),
+            ConfigError::DuplicatePort { port, fields } => write!(
+                f,
+                "port {} is shared by {}",
+                port,
+                fields.join(", ")
+            ),
+            ConfigError::NonPositive { field, value } => {
+                write!(f, "{} must be positive, got {}", field, value)
+            }
+            ConfigError::DuplicateIp { ip } => write!(
+                f,
+                "groundstation_ip and flightcomputer_ip must differ, both are {}",
+                ip
+            ),
+            ConfigError::FovOutOfRange { fov } => {
+                write!(f, "fov {:?} must have both components in (0, 180)", fov)
+            }
+            ConfigError::ThresholdInversion {
+                mapping_threshold,
+                flying_threshold,
+            } => write!(
+                f,
+                "mapping_threshold ({}) must not exceed flying_threshold ({})",
+                mapping_threshold, flying_threshold
+            ),
+        }
+    }
+}
+
+impl std::error::Error for ConfigError {}
+
+/// Validate a [`ManagerConfig`], collecting every problem found rather than
+/// stopping at the first one.
+pub fn validate(config: &ManagerConfig) -> Result<(), Vec<ConfigError>> {
+    let mut errors = Vec::new();
+
+    check_polygon("coordinates.mapping_area", &config.coordinates.mapping_area, &mut errors);
+    check_polygon("coordinates.target_area", &config.coordinates.target_area, &mut errors);
+    for zone in &config.coordinates.no_fly_zones {
+        check_polygon("coordinates.no_fly_zones", &zone.points, &mut errors);
+    }
+
+    check_positive(
+        "aircraft_properties.turn_radius",
+        config.aircraft_properties.turn_radius,
+        &mut errors,
+    );
+    check_positive(
+        "aircraft_properties.velocity",
+        config.aircraft_properties.velocity,
+        &mut errors,
+    );
+    check_positive(
+        "coordinates.flying_threshold",
+        config.coordinates.flying_threshold,
+        &mut errors,
+    );
+    check_positive(
+        "coordinates.mapping_threshold",
+        config.coordinates.mapping_threshold,
+        &mut errors,
+    );
+
+    if config.coordinates.mapping_threshold > config.coordinates.flying_threshold {
+        errors.push(ConfigError::ThresholdInversion {
+            mapping_threshold: config.coordinates.mapping_threshold,
+            flying_threshold: config.coordinates.flying_threshold,
+        });
+    }
+
+    let (fov_h, fov_v) = config.sauron_config.fov;
+    let in_range = |v: f64| v > 0.0 && v < 180.0;
+    if !in_range(fov_h) || !in_range(fov_v) {
+        errors.push(ConfigError::FovOutOfRange {
+            fov: config.sauron_config.fov,
+        });
+    }
+
+    check_duplicate_ports(config, &mut errors);
+
+    if config.commconfig.groundstation_ip == config.commconfig.flightcomputer_ip {
+        errors.push(ConfigError::DuplicateIp {
+            ip: config.commconfig.groundstation_ip.clone(),
+        });
+    }
+
+    if errors.is_empty() {
+        Ok(())
+    } else {
+        Err(errors)
+    }
+}
+
+fn check_positive(field: &'static str, value: f64, errors: &mut Vec<ConfigError>) {
+    if value <= 0.0 {
+        errors.push(ConfigError::NonPositive { field, value });
+    }
+}
+
+fn check_polygon(field: &'static str, points: &[Point], errors: &mut Vec<ConfigError>) {
+    if points.len() < 3 || is_collinear(points) {
+        errors.push(ConfigError::DegeneratePolygon {
+            field,
+            points: points.len(),
+        });
+    }
+}
+
+/// A polygon is degenerate if every point lies on the same line, i.e. every
+/// cross product of consecutive edge vectors is (near) zero.
+fn is_collinear(points: &[Point]) -> bool {
+    if points.len() < 3 {
+        return true;
+    }
+    points.windows(3).all(|w| {
+        let (a, b, c) = (&w[0], &w[1], &w[2]);
+        let cross = (b.x - a.x) * (c.y - a.y) - (b.y - a.y) * (c.x - a.x);
+        cross.abs() < f64::EPSILON
+    })
+}
+
+fn check_duplicate_ports(config: &ManagerConfig, errors: &mut Vec<ConfigError>) {
+    let ports: [(&'static str, i32); 4] = [
+        ("commconfig.dad_gnc_port", config.commconfig.dad_gnc_port),
+        ("commconfig.gnc_dad_port", config.commconfig.gnc_dad_port),
+        ("commconfig.dad_sauron_port", config.commconfig.dad_sauron_port),
+        ("commconfig.sauron_dad_port", config.commconfig.sauron_dad_port),
+    ];
+
+    for i in 0..ports.len() {
+        for j in (i + 1)..ports.len() {
+            if ports[i].1 == ports[j].1 {
+                errors.push(ConfigError::DuplicatePort {
+                    port: ports[i].1,
+                    fields: vec![ports[i].0, ports[j].0],
+                });
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn valid_config() -> ManagerConfig {
+        ManagerConfig::default()
+    }
+
+    #[test]
+    fn collinear_polygon_is_rejected() {
+        let mut config = valid_config();
+        config.coordinates.mapping_area = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 1.0 },
+            Point { x: 2.0, y: 2.0 },
+        ];
+        let errors = validate(&config).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(
+            e,
+            ConfigError::DegeneratePolygon { field, .. } if *field == "coordinates.mapping_area"
+        )));
+    }
+
+    #[test]
+    fn non_collinear_polygon_is_accepted() {
+        let mut config = valid_config();
+        config.coordinates.mapping_area = vec![
+            Point { x: 0.0, y: 0.0 },
+            Point { x: 1.0, y: 0.0 },
+            Point { x: 0.0, y: 1.0 },
+        ];
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn duplicate_ports_across_all_four_fields_are_flagged() {
+        let mut config = valid_config();
+        config.commconfig.dad_gnc_port = 9000;
+        config.commconfig.gnc_dad_port = 9000;
+        config.commconfig.dad_sauron_port = 9000;
+        config.commconfig.sauron_dad_port = 9000;
+        let errors = validate(&config).unwrap_err();
+        let duplicate_count = errors
+            .iter()
+            .filter(|e| matches!(e, ConfigError::DuplicatePort { port: 9000, .. }))
+            .count();
+        // 4 ports all equal -> C(4, 2) = 6 pairwise duplicates reported.
+        assert_eq!(duplicate_count, 6);
+    }
+
+    #[test]
+    fn fov_at_boundary_is_rejected() {
+        let mut config = valid_config();
+        config.sauron_config.fov = (0.0, 90.0);
+        assert!(validate(&config).is_err());
+
+        let mut config = valid_config();
+        config.sauron_config.fov = (90.0, 180.0);
+        assert!(validate(&config).is_err());
+    }
+
+    #[test]
+    fn fov_inside_range_is_accepted() {
+        let mut config = valid_config();
+        config.sauron_config.fov = (93.0, 81.0);
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn mapping_threshold_equal_to_flying_threshold_is_accepted() {
+        let mut config = valid_config();
+        config.coordinates.flying_threshold = 100.0;
+        config.coordinates.mapping_threshold = 100.0;
+        assert!(validate(&config).is_ok());
+    }
+
+    #[test]
+    fn mapping_threshold_greater_than_flying_threshold_is_rejected() {
+        let mut config = valid_config();
+        config.coordinates.flying_threshold = 50.0;
+        config.coordinates.mapping_threshold = 100.0;
+        let errors = validate(&config).unwrap_err();
+        assert!(errors.iter().any(|e| matches!(e, ConfigError::ThresholdInversion { .. })));
+    }
+}